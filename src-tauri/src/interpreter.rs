@@ -0,0 +1,228 @@
+use crate::session::{SessionKind, SessionManager};
+use crate::transcription;
+use crate::translate;
+use crate::tts::{self, Completion};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::{AppHandle, Emitter, Listener, State};
+use tokio::sync::mpsc;
+
+/// A finalized transcription segment paired with its translation, as
+/// broadcast to the frontend while it's queued and again once spoken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct InterpreterSegment {
+    original: String,
+    translated: String,
+    spoken: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptionResultPayload {
+    session_id: String,
+    text: String,
+    is_final: bool,
+}
+
+struct QueuedSegment {
+    segment: InterpreterSegment,
+    target_lang: String,
+    voice_preset: String,
+}
+
+/// A live interpreter session's speech queue and "currently speaking" flag.
+/// Scoped per speech session id so two concurrent `start_live_interpreter`
+/// calls (e.g. different language pairs) don't interleave each other's
+/// segments or serialize on one shared flag.
+#[derive(Default)]
+struct InterpreterQueue {
+    segments: VecDeque<QueuedSegment>,
+    speaking: bool,
+}
+
+fn interpreter_sessions() -> &'static Mutex<HashMap<String, InterpreterQueue>> {
+    static SESSIONS: OnceLock<Mutex<HashMap<String, InterpreterQueue>>> = OnceLock::new();
+    SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// What `stop_live_interpreter` needs to tear a session down: the
+/// `transcription-result` listener to remove, and the speech session id
+/// its queue is filed under.
+struct InterpreterHandle {
+    listener: tauri::EventId,
+    speech_session_id: String,
+}
+
+fn interpreter_handles() -> &'static Mutex<HashMap<String, InterpreterHandle>> {
+    static HANDLES: OnceLock<Mutex<HashMap<String, InterpreterHandle>>> = OnceLock::new();
+    HANDLES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drops any translated segments that haven't been spoken yet across all
+/// live interpreter sessions, so `stop_speech(drop_pending: true)` can
+/// flush the backlog instead of working through it.
+pub fn clear_pending() {
+    for queue in interpreter_sessions().lock().unwrap().values_mut() {
+        queue.segments.clear();
+    }
+}
+
+/// Wires transcription, translation and speech together: each finalized
+/// transcription is translated and queued for speech, emitting
+/// `interpreter-segment` events as segments are queued and then spoken.
+#[tauri::command]
+pub async fn start_live_interpreter(
+    app_handle: AppHandle,
+    session_manager: State<'_, Arc<SessionManager>>,
+    tts_state: State<'_, tts::TtsState>,
+    source_lang: String,
+    target_lang: String,
+    voice_preset: String,
+) -> Result<String, String> {
+    let backend = tts_state.0.clone();
+    let session_id =
+        transcription::start_transcription(app_handle.clone(), session_manager.clone(), Some(source_lang.clone())).await?;
+
+    // Tracked separately from the transcription session so `list_sessions`
+    // covers the speech half of the pipeline too, and so this session's
+    // queue never interleaves with another concurrent
+    // `start_live_interpreter` call's. Its cancellation token is not
+    // observed; `stop_live_interpreter` removes the session instead.
+    let (speech_session_id, _speech_cancellation) = session_manager.start(SessionKind::Speech);
+    interpreter_sessions()
+        .lock()
+        .unwrap()
+        .insert(speech_session_id.clone(), InterpreterQueue::default());
+
+    // Finals are handed to a single worker that translates them one at a
+    // time, in arrival order, instead of racing an independent task per
+    // final into the shared queue (where a slow translation could finish
+    // after a later, faster one and speak segments out of order).
+    let (job_tx, mut job_rx) = mpsc::unbounded_channel::<String>();
+
+    let session_id_for_listener = session_id.clone();
+    let listener = app_handle.listen("transcription-result", move |event| {
+        let Ok(payload) = serde_json::from_str::<TranscriptionResultPayload>(event.payload()) else {
+            return;
+        };
+        if payload.session_id != session_id_for_listener || !payload.is_final {
+            return;
+        }
+        let _ = job_tx.send(payload.text);
+    });
+
+    let app_for_worker = app_handle.clone();
+    let worker_speech_session_id = speech_session_id.clone();
+    tokio::spawn(async move {
+        while let Some(text) = job_rx.recv().await {
+            let translated = match translate::translate(
+                text.clone(),
+                target_lang.clone(),
+                Some(source_lang.clone()),
+                None,
+                None,
+            )
+            .await
+            {
+                Ok(result) => result.translated_text,
+                Err(e) => {
+                    let _ = app_for_worker.emit("transcription-error", e);
+                    continue;
+                }
+            };
+
+            let segment = InterpreterSegment {
+                original: text,
+                translated,
+                spoken: false,
+            };
+            let _ = app_for_worker.emit("interpreter-segment", segment.clone());
+
+            if let Some(queue) = interpreter_sessions().lock().unwrap().get_mut(&worker_speech_session_id) {
+                queue.segments.push_back(QueuedSegment {
+                    segment,
+                    target_lang: target_lang.clone(),
+                    voice_preset: voice_preset.clone(),
+                });
+            }
+            drive_queue(app_for_worker.clone(), backend.clone(), worker_speech_session_id.clone());
+        }
+    });
+
+    interpreter_handles().lock().unwrap().insert(
+        session_id.clone(),
+        InterpreterHandle {
+            listener,
+            speech_session_id,
+        },
+    );
+
+    Ok(session_id)
+}
+
+/// Tears down a live interpreter session: cancels its transcription and
+/// speech sessions and removes the `transcription-result` listener
+/// `start_live_interpreter` registered, so repeated start/stop cycles don't
+/// leak listeners.
+#[tauri::command]
+pub async fn stop_live_interpreter(
+    app_handle: AppHandle,
+    session_manager: State<'_, Arc<SessionManager>>,
+    session_id: String,
+) -> Result<(), String> {
+    let Some(handle) = interpreter_handles().lock().unwrap().remove(&session_id) else {
+        return Err(format!("No active live interpreter session with id {session_id}"));
+    };
+
+    app_handle.unlisten(handle.listener);
+    interpreter_sessions().lock().unwrap().remove(&handle.speech_session_id);
+    session_manager.finish(&handle.speech_session_id);
+    session_manager.cancel(&session_id)
+}
+
+/// Speaks a session's queued segments one at a time. Each utterance's
+/// completion callback re-enters this function for the same session id so
+/// its translations never overlap while transcription keeps producing new
+/// ones, without affecting any other session's queue.
+fn drive_queue(app_handle: AppHandle, backend: Arc<dyn tts::TtsBackend>, speech_session_id: String) {
+    let queued = {
+        let mut sessions = interpreter_sessions().lock().unwrap();
+        let Some(queue) = sessions.get_mut(&speech_session_id) else {
+            return;
+        };
+        if queue.speaking {
+            return;
+        }
+        let Some(queued) = queue.segments.pop_front() else {
+            return;
+        };
+        queue.speaking = true;
+        queued
+    };
+
+    let voice = tts::resolve_voice(backend.as_ref(), &queued.target_lang, &queued.voice_preset);
+    let opts = tts::UtteranceOptions {
+        voice_id: voice.map(|v| v.id),
+        ..Default::default()
+    };
+
+    let app_for_completion = app_handle.clone();
+    let backend_for_completion = backend.clone();
+    let session_id_for_completion = speech_session_id.clone();
+    let mut spoken_segment = queued.segment.clone();
+    let on_complete: Completion = Box::new(move || {
+        spoken_segment.spoken = true;
+        let _ = app_for_completion.emit("interpreter-segment", spoken_segment);
+        if let Some(queue) = interpreter_sessions().lock().unwrap().get_mut(&session_id_for_completion) {
+            queue.speaking = false;
+        }
+        drive_queue(app_for_completion, backend_for_completion, session_id_for_completion);
+    });
+
+    if let Err(message) = backend.speak(&queued.segment.translated, &opts, Some(on_complete)) {
+        let _ = app_handle.emit("transcription-error", translate::TranslationError { message });
+        if let Some(queue) = interpreter_sessions().lock().unwrap().get_mut(&speech_session_id) {
+            queue.speaking = false;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::State;
+use tokio_util::sync::CancellationToken;
+
+/// What kind of job a session is tracking. `Speech` is tracked for
+/// `list_sessions` visibility; transcription is the first consumer that
+/// actually cancels via its token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionKind {
+    Transcription,
+    Speech,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionInfo {
+    pub session_id: String,
+    pub kind: SessionKind,
+}
+
+struct SessionHandle {
+    kind: SessionKind,
+    cancellation: CancellationToken,
+}
+
+/// Hands out opaque session ids and tracks the cancellation token for each
+/// active job, replacing the single global `Mutex<bool>` that only allowed
+/// one transcription to run at a time.
+#[derive(Default)]
+pub struct SessionManager {
+    next_id: AtomicU64,
+    sessions: Mutex<HashMap<String, SessionHandle>>,
+}
+
+impl SessionManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new session and returns its id plus the token that
+    /// the owning task should poll (or select on) to know when to stop.
+    pub fn start(&self, kind: SessionKind) -> (String, CancellationToken) {
+        let id = format!("sess_{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let cancellation = CancellationToken::new();
+        self.sessions.lock().unwrap().insert(
+            id.clone(),
+            SessionHandle {
+                kind,
+                cancellation: cancellation.clone(),
+            },
+        );
+        (id, cancellation)
+    }
+
+    pub fn cancel(&self, session_id: &str) -> Result<(), String> {
+        let sessions = self.sessions.lock().unwrap();
+        match sessions.get(session_id) {
+            Some(handle) => {
+                handle.cancellation.cancel();
+                Ok(())
+            }
+            None => Err(format!("No active session with id {session_id}")),
+        }
+    }
+
+    /// Removes a session once its owning task has actually exited.
+    pub fn finish(&self, session_id: &str) {
+        self.sessions.lock().unwrap().remove(session_id);
+    }
+
+    pub fn list(&self) -> Vec<SessionInfo> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, handle)| SessionInfo {
+                session_id: id.clone(),
+                kind: handle.kind,
+            })
+            .collect()
+    }
+}
+
+#[tauri::command]
+pub fn list_sessions(session_manager: State<'_, Arc<SessionManager>>) -> Vec<SessionInfo> {
+    session_manager.list()
+}
@@ -0,0 +1,283 @@
+use crate::session::{SessionKind, SessionManager};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+use tokio::sync::mpsc;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_util::sync::CancellationToken;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TranscriptionResult {
+    session_id: String,
+    text: String,
+    is_final: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TranscriptionError {
+    session_id: String,
+    pub(crate) message: String,
+}
+
+const SAMPLE_RATE: u32 = 16_000;
+const FRAME_MILLIS: u32 = 100;
+const FRAME_SAMPLES: usize = (SAMPLE_RATE * FRAME_MILLIS / 1000) as usize;
+
+#[cfg(target_os = "macos")]
+fn check_microphone_permission() -> Result<(), String> {
+    // For now, just return Ok without checking any permission
+    // In a real implementation, this would check AVAudioSession.recordPermission
+    println!("TRANSCRIPTION DEBUG: Checking microphone permissions (simulated)");
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+fn check_microphone_permission() -> Result<(), String> {
+    Err("Microphone permission check not supported on this platform".to_string())
+}
+
+/// Starts a new transcription session and returns its id. Multiple sessions
+/// can run concurrently; each gets its own cancellation token instead of
+/// sharing one global "is transcribing" flag.
+#[tauri::command]
+pub async fn start_transcription(
+    app_handle: AppHandle,
+    session_manager: State<'_, Arc<SessionManager>>,
+    language: Option<String>,
+) -> Result<String, String> {
+    println!("TRANSCRIPTION DEBUG: Starting transcription with language: {:?}", language);
+
+    if let Err(e) = check_microphone_permission() {
+        let error_msg = format!("Microphone permission denied: {}", e);
+        println!("TRANSCRIPTION DEBUG: {}", error_msg);
+        return Err(error_msg);
+    }
+
+    let (session_id, cancellation) = session_manager.start(SessionKind::Transcription);
+    println!("TRANSCRIPTION DEBUG: Session {} starting", session_id);
+
+    #[cfg(target_os = "macos")]
+    {
+        let lang = language.unwrap_or_else(|| "en-US".to_string());
+        println!("TRANSCRIPTION DEBUG: Using language: {}", lang);
+
+        let app_handle_clone = app_handle.clone();
+        let manager = session_manager.inner().clone();
+        let id_for_task = session_id.clone();
+        tokio::spawn(async move {
+            println!("TRANSCRIPTION DEBUG: Starting streaming transcription task for session {}", id_for_task);
+
+            match stream_transcription(app_handle_clone.clone(), lang, id_for_task.clone(), cancellation).await {
+                Ok(()) => {
+                    println!("TRANSCRIPTION DEBUG: Speech recognition completed successfully");
+                },
+                Err(e) => {
+                    println!("TRANSCRIPTION DEBUG: Speech recognition failed with error: {}", e);
+                    let _ = app_handle_clone.emit("transcription-error", TranscriptionError {
+                        session_id: id_for_task.clone(),
+                        message: e,
+                    });
+                }
+            }
+
+            manager.finish(&id_for_task);
+        });
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = cancellation;
+        session_manager.finish(&session_id);
+        return Err("Speech recognition is only supported on macOS".to_string());
+    }
+
+    Ok(session_id)
+}
+
+/// Cancels the transcription session with this id; other active sessions
+/// are left running.
+#[tauri::command]
+pub async fn stop_transcription(
+    session_manager: State<'_, Arc<SessionManager>>,
+    session_id: String,
+) -> Result<(), String> {
+    session_manager.cancel(&session_id)
+}
+
+/// A single partial or final hypothesis from the transcription provider.
+#[derive(Debug, Deserialize)]
+struct ProviderResult {
+    transcript: String,
+    is_final: bool,
+}
+
+/// Downmixes multi-channel input to mono and linearly resamples it from
+/// the device's native rate to `SAMPLE_RATE`, carrying the fractional
+/// position and trailing sample across calls so consecutive audio
+/// callbacks interpolate seamlessly at their boundary.
+struct Resampler {
+    ratio: f64,
+    acc: f64,
+    prev: f32,
+}
+
+impl Resampler {
+    fn new(input_rate: u32, output_rate: u32) -> Self {
+        Self {
+            ratio: input_rate as f64 / output_rate as f64,
+            acc: 0.0,
+            prev: 0.0,
+        }
+    }
+
+    /// Downmixes and resamples one callback's worth of interleaved
+    /// `channels`-channel samples, appending i16 output samples to `out`.
+    fn process(&mut self, input: &[f32], channels: u16, out: &mut Vec<i16>) {
+        let channels = channels.max(1) as usize;
+        let frame_count = input.len() / channels;
+        if frame_count == 0 {
+            return;
+        }
+
+        let mut combined = Vec::with_capacity(frame_count + 1);
+        combined.push(self.prev);
+        for frame in input.chunks_exact(channels).take(frame_count) {
+            combined.push(frame.iter().sum::<f32>() / channels as f32);
+        }
+
+        // `index` is a fractional position in `combined`, where `index == 1.0`
+        // lines up with this chunk's first sample (`combined[0]` is the
+        // previous chunk's trailing sample, used so interpolation doesn't
+        // glitch at the callback boundary).
+        let mut index = 1.0 + self.acc;
+        while (index.floor() as usize) + 1 < combined.len() {
+            let i = index.floor() as usize;
+            let frac = (index - i as f64) as f32;
+            let sample = combined[i] + (combined[i + 1] - combined[i]) * frac;
+            out.push((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16);
+            index += self.ratio;
+        }
+
+        self.acc = index - 1.0 - frame_count as f64;
+        self.prev = *combined.last().unwrap();
+    }
+}
+
+/// Captures microphone audio at 16kHz mono, streams it in ~100ms frames to a
+/// WebSocket speech-to-text provider, and emits `transcription-result`
+/// events as partial and final hypotheses come back. Exits as soon as the
+/// session's cancellation token fires.
+#[cfg(target_os = "macos")]
+async fn stream_transcription(
+    app_handle: AppHandle,
+    language: String,
+    session_id: String,
+    cancellation: CancellationToken,
+) -> Result<(), String> {
+    let stt_url = std::env::var("STT_WEBSOCKET_URL")
+        .map_err(|_| "STT_WEBSOCKET_URL not set in environment".to_string())?;
+
+    let (ws_stream, _) = connect_async(format!("{stt_url}?language={language}&sample_rate={SAMPLE_RATE}"))
+        .await
+        .map_err(|e| format!("Failed to connect to transcription service: {e}"))?;
+    let (mut ws_write, mut ws_read) = ws_stream.split();
+
+    let (frame_tx, mut frame_rx) = mpsc::unbounded_channel::<Vec<i16>>();
+
+    let host = cpal::default_host();
+    let device = host
+        .default_input_device()
+        .ok_or_else(|| "No microphone input device available".to_string())?;
+
+    // Most hardware doesn't support mono 16kHz capture directly (built-in
+    // and USB mics commonly default to 44.1/48kHz, often in stereo), and
+    // `build_input_stream` fails outright if asked for a config the device
+    // doesn't list. Open the device at its own default config instead, and
+    // downmix + resample to the 16kHz mono the STT provider expects.
+    let supported_config = device
+        .default_input_config()
+        .map_err(|e| format!("Failed to query microphone input config: {e}"))?;
+    let sample_format = supported_config.sample_format();
+    let input_channels = supported_config.channels();
+    let input_sample_rate = supported_config.sample_rate().0;
+    let config = cpal::StreamConfig {
+        channels: input_channels,
+        sample_rate: supported_config.sample_rate(),
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    let mut frame_buf: Vec<i16> = Vec::with_capacity(FRAME_SAMPLES);
+    let mut resampler = Resampler::new(input_sample_rate, SAMPLE_RATE);
+    let mut resampled: Vec<i16> = Vec::new();
+    let stream = match sample_format {
+        cpal::SampleFormat::F32 => device.build_input_stream(
+            &config,
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                resampler.process(data, input_channels, &mut resampled);
+                for sample in resampled.drain(..) {
+                    frame_buf.push(sample);
+                    if frame_buf.len() >= FRAME_SAMPLES {
+                        let _ = frame_tx.send(std::mem::replace(&mut frame_buf, Vec::with_capacity(FRAME_SAMPLES)));
+                    }
+                }
+            },
+            |err| println!("TRANSCRIPTION DEBUG: input stream error: {err}"),
+            None,
+        ),
+        cpal::SampleFormat::I16 => device.build_input_stream(
+            &config,
+            move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                let floats: Vec<f32> = data.iter().map(|&s| s as f32 / i16::MAX as f32).collect();
+                resampler.process(&floats, input_channels, &mut resampled);
+                for sample in resampled.drain(..) {
+                    frame_buf.push(sample);
+                    if frame_buf.len() >= FRAME_SAMPLES {
+                        let _ = frame_tx.send(std::mem::replace(&mut frame_buf, Vec::with_capacity(FRAME_SAMPLES)));
+                    }
+                }
+            },
+            |err| println!("TRANSCRIPTION DEBUG: input stream error: {err}"),
+            None,
+        ),
+        other => return Err(format!("Unsupported microphone sample format: {other:?}")),
+    }
+    .map_err(|e| format!("Failed to open microphone: {e}"))?;
+
+    stream.play().map_err(|e| format!("Failed to start microphone capture: {e}"))?;
+
+    loop {
+        tokio::select! {
+            _ = cancellation.cancelled() => break,
+            frame = frame_rx.recv() => {
+                let Some(samples) = frame else { break };
+                let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+                ws_write
+                    .send(Message::Binary(bytes))
+                    .await
+                    .map_err(|e| format!("Failed to stream audio frame: {e}"))?;
+            }
+            message = ws_read.next() => {
+                match message {
+                    Some(Ok(Message::Text(payload))) => {
+                        if let Ok(result) = serde_json::from_str::<ProviderResult>(&payload) {
+                            let _ = app_handle.emit("transcription-result", TranscriptionResult {
+                                session_id: session_id.clone(),
+                                text: result.transcript,
+                                is_final: result.is_final,
+                            });
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(format!("Transcription stream error: {e}")),
+                }
+            }
+        }
+    }
+
+    drop(stream);
+    let _ = ws_write.send(Message::Close(None)).await;
+    Ok(())
+}
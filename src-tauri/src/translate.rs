@@ -0,0 +1,181 @@
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TranslationResult {
+    pub(crate) translated_text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TranslationError {
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UsageResult {
+    character_count: u64,
+    character_limit: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLUsageResponse {
+    character_count: u64,
+    character_limit: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LanguageInfo {
+    language: String,
+    name: String,
+}
+
+fn resolve_api_key() -> Result<String, TranslationError> {
+    let api_key = env::var("DEEPL_API_KEY").map_err(|_| TranslationError {
+        message: "DeepL API key not found in environment.".to_string(),
+    })?;
+
+    if api_key.is_empty() || api_key == "your_deepl_api_key_here" {
+        return Err(TranslationError {
+            message: "API key is empty or is a placeholder. Please check your .env file.".to_string(),
+        });
+    }
+
+    Ok(api_key)
+}
+
+// Free-tier keys are suffixed with ":fx" and are rejected by the paid host
+// (and vice versa), which otherwise surfaces as an opaque "DeepL API Error".
+fn deepl_host(api_key: &str) -> &'static str {
+    if api_key.ends_with(":fx") {
+        "https://api-free.deepl.com"
+    } else {
+        "https://api.deepl.com"
+    }
+}
+
+async fn deepl_error(response: Response) -> TranslationError {
+    let status = response.status();
+    let error_body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Could not retrieve error body.".to_string());
+    let message = format!("DeepL API Error (Status: {}): {}", status, error_body);
+    println!("TRANSLATION DEBUG: {}", message);
+    TranslationError { message }
+}
+
+#[tauri::command]
+pub async fn translate(
+    text: String,
+    target_lang: String,
+    source_lang: Option<String>,
+    formality: Option<String>,
+    glossary_id: Option<String>,
+) -> Result<TranslationResult, TranslationError> {
+    let api_key = resolve_api_key()?;
+    let client = Client::new();
+
+    let mut params = vec![("text", text.as_str()), ("target_lang", target_lang.as_str())];
+
+    if let Some(ref source) = source_lang {
+        params.push(("source_lang", source.as_str()));
+    }
+    if let Some(ref formality) = formality {
+        params.push(("formality", formality.as_str()));
+    }
+    if let Some(ref glossary_id) = glossary_id {
+        params.push(("glossary_id", glossary_id.as_str()));
+    }
+
+    let response = client
+        .post(format!("{}/v2/translate", deepl_host(&api_key)))
+        .header("Authorization", format!("DeepL-Auth-Key {}", api_key))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .form(&params)
+        .send()
+        .await
+        .map_err(|e| TranslationError {
+            message: format!("Failed to connect to DeepL API: {}", e),
+        })?;
+
+    if !response.status().is_success() {
+        return Err(deepl_error(response).await);
+    }
+
+    let deepl_response: DeepLResponse = response.json().await.map_err(|e| TranslationError {
+        message: format!("Failed to parse DeepL response: {}", e),
+    })?;
+
+    let translated_text = deepl_response
+        .translations
+        .first()
+        .map(|t| t.text.clone())
+        .ok_or_else(|| TranslationError {
+            message: "No translation found in DeepL response.".to_string(),
+        })?;
+
+    Ok(TranslationResult { translated_text })
+}
+
+#[tauri::command]
+pub async fn deepl_usage() -> Result<UsageResult, TranslationError> {
+    let api_key = resolve_api_key()?;
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{}/v2/usage", deepl_host(&api_key)))
+        .header("Authorization", format!("DeepL-Auth-Key {}", api_key))
+        .send()
+        .await
+        .map_err(|e| TranslationError {
+            message: format!("Failed to connect to DeepL API: {}", e),
+        })?;
+
+    if !response.status().is_success() {
+        return Err(deepl_error(response).await);
+    }
+
+    let usage: DeepLUsageResponse = response.json().await.map_err(|e| TranslationError {
+        message: format!("Failed to parse DeepL response: {}", e),
+    })?;
+
+    Ok(UsageResult {
+        character_count: usage.character_count,
+        character_limit: usage.character_limit,
+    })
+}
+
+#[tauri::command]
+pub async fn deepl_languages(kind: String) -> Result<Vec<LanguageInfo>, TranslationError> {
+    let api_key = resolve_api_key()?;
+    let client = Client::new();
+
+    let response = client
+        .get(format!("{}/v2/languages", deepl_host(&api_key)))
+        .header("Authorization", format!("DeepL-Auth-Key {}", api_key))
+        .query(&[("type", kind.as_str())])
+        .send()
+        .await
+        .map_err(|e| TranslationError {
+            message: format!("Failed to connect to DeepL API: {}", e),
+        })?;
+
+    if !response.status().is_success() {
+        return Err(deepl_error(response).await);
+    }
+
+    response.json().await.map_err(|e| TranslationError {
+        message: format!("Failed to parse DeepL response: {}", e),
+    })
+}
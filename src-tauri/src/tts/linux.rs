@@ -0,0 +1,87 @@
+use super::{Completion, Features, TtsBackend, UtteranceOptions, Voice};
+use speech_dispatcher::{Connection, Mode, Priority};
+use std::sync::{Arc, Mutex};
+
+pub struct LinuxBackend {
+    connection: Mutex<Connection>,
+    pending_completion: Arc<Mutex<Option<Completion>>>,
+}
+
+impl LinuxBackend {
+    pub fn new() -> Self {
+        let mut connection = Connection::open("speech-translator", "tts", "main", Mode::Threaded)
+            .expect("failed to connect to Speech Dispatcher");
+
+        let pending_completion: Arc<Mutex<Option<Completion>>> = Arc::new(Mutex::new(None));
+        let pending_for_callback = pending_completion.clone();
+        connection.on_end(move |_msg_id| {
+            if let Some(completion) = pending_for_callback.lock().unwrap().take() {
+                completion();
+            }
+        });
+
+        Self {
+            connection: Mutex::new(connection),
+            pending_completion,
+        }
+    }
+}
+
+impl TtsBackend for LinuxBackend {
+    fn speak(&self, text: &str, opts: &UtteranceOptions, on_complete: Option<Completion>) -> Result<(), String> {
+        let mut connection = self.connection.lock().map_err(|e| e.to_string())?;
+        if let Some(voice_id) = &opts.voice_id {
+            connection.set_synthesis_voice(voice_id);
+        }
+        if let Some(rate) = opts.rate {
+            connection.set_voice_rate(((rate - 1.0) * 100.0) as i32);
+        }
+        if let Some(pitch) = opts.pitch {
+            connection.set_voice_pitch(((pitch - 1.0) * 100.0) as i32);
+        }
+        if let Some(volume) = opts.volume {
+            connection.set_volume(((volume - 1.0) * 100.0) as i32);
+        }
+
+        if let Some(on_complete) = on_complete {
+            *self.pending_completion.lock().map_err(|e| e.to_string())? = Some(on_complete);
+        }
+        connection.say(Priority::Text, text);
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let connection = self.connection.lock().map_err(|e| e.to_string())?;
+        connection.stop();
+        Ok(())
+    }
+
+    fn voices(&self) -> Vec<Voice> {
+        let connection = match self.connection.lock() {
+            Ok(c) => c,
+            Err(_) => return Vec::new(),
+        };
+        connection
+            .list_synthesis_voices()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| Voice {
+                id: v.name.clone(),
+                name: v.name,
+                language: v.language,
+                gender: None,
+            })
+            .collect()
+    }
+
+    fn features(&self) -> Features {
+        Features {
+            rate: true,
+            pitch: true,
+            volume: true,
+            // Speech Dispatcher's `say` takes plain text; SSML would need a
+            // module-specific escape hatch we don't rely on here.
+            ssml: false,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Completion, Features, TtsBackend, UtteranceOptions, Voice};
+use objc2::rc::Retained;
+use objc2::{define_class, msg_send, DefinedClass};
+use objc2_av_foundation::{
+    AVSpeechBoundary, AVSpeechSynthesisVoice, AVSpeechSynthesisVoiceGender, AVSpeechSynthesizer,
+    AVSpeechSynthesizerDelegate, AVSpeechUtterance,
+};
+use objc2_foundation::{MainThreadMarker, NSObject, NSObjectProtocol, NSString};
+use std::sync::Mutex;
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "SpeechCompletionDelegate"]
+    #[ivars = Mutex<Option<Completion>>]
+    struct SpeechCompletionDelegate;
+
+    unsafe impl NSObjectProtocol for SpeechCompletionDelegate {}
+
+    unsafe impl AVSpeechSynthesizerDelegate for SpeechCompletionDelegate {
+        #[unsafe(method(speechSynthesizer:didFinishSpeechUtterance:))]
+        fn did_finish(&self, _synthesizer: &AVSpeechSynthesizer, _utterance: &AVSpeechUtterance) {
+            if let Some(completion) = self.ivars().lock().unwrap().take() {
+                completion();
+            }
+        }
+
+        // `stop()` calls `stopSpeakingAtBoundary`, which fires this callback
+        // instead of `didFinishSpeechUtterance:`. Without it, `on_complete`
+        // never runs for a stopped utterance and callers waiting on it
+        // (e.g. the live interpreter's speech queue) stay wedged forever.
+        #[unsafe(method(speechSynthesizer:didCancelSpeechUtterance:))]
+        fn did_cancel(&self, _synthesizer: &AVSpeechSynthesizer, _utterance: &AVSpeechUtterance) {
+            if let Some(completion) = self.ivars().lock().unwrap().take() {
+                completion();
+            }
+        }
+    }
+);
+
+impl SpeechCompletionDelegate {
+    fn new(mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(Mutex::new(None));
+        unsafe { msg_send![super(this), init] }
+    }
+
+    fn set_completion(&self, completion: Completion) {
+        *self.ivars().lock().unwrap() = Some(completion);
+    }
+}
+
+pub struct MacOsBackend {
+    synthesizer: Mutex<Retained<AVSpeechSynthesizer>>,
+    delegate: Retained<SpeechCompletionDelegate>,
+}
+
+impl MacOsBackend {
+    pub fn new() -> Self {
+        let mtm = MainThreadMarker::new().expect("TTS backend must be created on the main thread");
+        let synthesizer = unsafe { AVSpeechSynthesizer::new(mtm) };
+        let delegate = SpeechCompletionDelegate::new(mtm);
+        unsafe { synthesizer.setDelegate(Some(objc2::runtime::ProtocolObject::from_ref(&*delegate))) };
+        Self {
+            synthesizer: Mutex::new(synthesizer),
+            delegate,
+        }
+    }
+}
+
+impl TtsBackend for MacOsBackend {
+    fn speak(&self, text: &str, opts: &UtteranceOptions, on_complete: Option<Completion>) -> Result<(), String> {
+        let synthesizer = self.synthesizer.lock().map_err(|e| e.to_string())?;
+
+        let utterance = if let Some(ssml) = &opts.ssml {
+            unsafe { AVSpeechUtterance::initWithSSMLRepresentation(AVSpeechUtterance::alloc(), &NSString::from_str(ssml)) }
+                .ok_or_else(|| "invalid SSML representation".to_string())?
+        } else {
+            unsafe { AVSpeechUtterance::speechUtteranceWithString(&NSString::from_str(text)) }
+        };
+
+        if let Some(voice_id) = &opts.voice_id {
+            if let Some(voice) =
+                unsafe { AVSpeechSynthesisVoice::voiceWithIdentifier(&NSString::from_str(voice_id)) }
+            {
+                unsafe { utterance.setVoice(Some(&voice)) };
+            }
+        }
+        if let Some(rate) = opts.rate {
+            // `rate` is a 1.0-centered multiplier; AVSpeechUtterance rates run
+            // from 0.0 to 1.0 with 0.5 as the default speed.
+            unsafe { utterance.setRate((rate * 0.5).clamp(0.0, 1.0)) };
+        }
+        if let Some(pitch) = opts.pitch {
+            unsafe { utterance.setPitchMultiplier(pitch) };
+        }
+        if let Some(volume) = opts.volume {
+            unsafe { utterance.setVolume(volume) };
+        }
+
+        if let Some(on_complete) = on_complete {
+            self.delegate.set_completion(on_complete);
+        }
+        unsafe { synthesizer.speakUtterance(&utterance) };
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let synthesizer = self.synthesizer.lock().map_err(|e| e.to_string())?;
+        unsafe { synthesizer.stopSpeakingAtBoundary(AVSpeechBoundary::Immediate) };
+        Ok(())
+    }
+
+    fn voices(&self) -> Vec<Voice> {
+        unsafe { AVSpeechSynthesisVoice::speechVoices() }
+            .iter()
+            .map(|v| Voice {
+                id: unsafe { v.identifier() }.to_string(),
+                name: unsafe { v.name() }.to_string(),
+                language: unsafe { v.language() }.to_string(),
+                gender: Some(
+                    match unsafe { v.gender() } {
+                        AVSpeechSynthesisVoiceGender::Male => "male",
+                        AVSpeechSynthesisVoiceGender::Female => "female",
+                        _ => "unspecified",
+                    }
+                    .to_string(),
+                ),
+            })
+            .collect()
+    }
+
+    fn features(&self) -> Features {
+        Features {
+            rate: true,
+            pitch: true,
+            volume: true,
+            ssml: true,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+#[cfg(all(unix, not(target_os = "macos")))]
+mod linux;
+
+/// A voice advertised by the active platform backend.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Voice {
+    pub id: String,
+    pub name: String,
+    pub language: String,
+    pub gender: Option<String>,
+}
+
+/// Per-utterance synthesis parameters. Fields are optional so callers can
+/// defer to the backend's defaults.
+#[derive(Debug, Clone, Default)]
+pub struct UtteranceOptions {
+    pub voice_id: Option<String>,
+    pub rate: Option<f32>,
+    pub pitch: Option<f32>,
+    pub volume: Option<f32>,
+    pub ssml: Option<String>,
+}
+
+/// Which of the optional `UtteranceOptions` controls a backend actually
+/// honors. `speak` uses this to reject a request up front with
+/// `unsupported_feature` instead of silently dropping a control the backend
+/// can't apply, mirroring the feature-query model in the `tts` crate.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Features {
+    pub rate: bool,
+    pub pitch: bool,
+    pub volume: bool,
+    pub ssml: bool,
+}
+
+/// Invoked once an utterance finishes, so callers can chain speech without
+/// overlapping it. When `speak` returns an error the completion is dropped
+/// without being called.
+pub type Completion = Box<dyn FnOnce() + Send>;
+
+/// Dispatches speech synthesis to whichever in-process engine the current
+/// platform exposes (AVFoundation on macOS, SAPI on Windows, Speech
+/// Dispatcher on Linux), mirroring the `Backends` enum in the `tts` crate.
+pub trait TtsBackend: Send + Sync {
+    fn speak(&self, text: &str, opts: &UtteranceOptions, on_complete: Option<Completion>) -> Result<(), String>;
+    fn stop(&self) -> Result<(), String>;
+    fn voices(&self) -> Vec<Voice>;
+    fn features(&self) -> Features;
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn new_backend() -> Box<dyn TtsBackend> {
+    Box::new(macos::MacOsBackend::new())
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn new_backend() -> Box<dyn TtsBackend> {
+    Box::new(windows::WindowsBackend::new())
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub(crate) fn new_backend() -> Box<dyn TtsBackend> {
+    Box::new(linux::LinuxBackend::new())
+}
+
+/// The process-wide backend instance, built once during app `.setup()` and
+/// handed out via Tauri's managed state. Kept as an `Arc` (rather than the
+/// state's usual owned field) so long-lived tasks like the live interpreter
+/// can clone a handle to it instead of borrowing from a command's `State`.
+pub struct TtsState(pub Arc<dyn TtsBackend>);
+
+/// Resolves the existing voice-preset shorthand ("cinematic" vs. default)
+/// onto a concrete voice advertised by the active backend. This is kept as
+/// a thin layer over `TtsBackend::voices` so presets stay out of the
+/// backend implementations themselves.
+pub fn resolve_voice(backend: &dyn TtsBackend, language_code: &str, voice_preset: &str) -> Option<Voice> {
+    let preset_name = match (language_code, voice_preset) {
+        ("es-ES", "cinematic") => "Diego",
+        ("es-ES", _) => "Mónica",
+        ("fr-FR", "cinematic") => "Thomas",
+        ("fr-FR", _) => "Amélie",
+        ("en-US", "cinematic") => "Alex",
+        _ => "Samantha",
+    };
+
+    let voices = backend.voices();
+    voices
+        .iter()
+        .find(|v| v.name == preset_name)
+        .cloned()
+        .or_else(|| voices.into_iter().find(|v| v.language == language_code))
+}
@@ -0,0 +1,144 @@
+use super::{Completion, Features, TtsBackend, UtteranceOptions, Voice};
+use std::sync::Mutex;
+use windows::core::{w, HSTRING, PCWSTR, PWSTR};
+use windows::Win32::Globalization::LCIDToLocaleName;
+use windows::Win32::Media::Speech::{
+    ISpObjectToken, ISpObjectTokenCategory, ISpVoice, SpObjectTokenCategory, SpVoice, SPCAT_VOICES, SPF_ASYNC,
+    SPF_IS_XML, SPF_PURGEBEFORESPEAK,
+};
+use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoTaskMemFree, CLSCTX_ALL, COINIT_MULTITHREADED};
+
+pub struct WindowsBackend {
+    voice: Mutex<ISpVoice>,
+}
+
+impl WindowsBackend {
+    pub fn new() -> Self {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+            let voice: ISpVoice =
+                CoCreateInstance(&SpVoice, None, CLSCTX_ALL).expect("SAPI voice is unavailable on this machine");
+            Self {
+                voice: Mutex::new(voice),
+            }
+        }
+    }
+}
+
+impl TtsBackend for WindowsBackend {
+    fn speak(&self, text: &str, opts: &UtteranceOptions, on_complete: Option<Completion>) -> Result<(), String> {
+        let voice = self.voice.lock().map_err(|e| e.to_string())?;
+        if let Some(rate) = opts.rate {
+            // SAPI rates are an integer in [-10, 10]; our rate is a 1.0-centered multiplier.
+            let sapi_rate = ((rate - 1.0) * 10.0).clamp(-10.0, 10.0) as i32;
+            unsafe { voice.SetRate(sapi_rate).map_err(|e| e.to_string())? };
+        }
+        if let Some(volume) = opts.volume {
+            // SAPI volume is a percentage in [0, 100].
+            unsafe { voice.SetVolume((volume * 100.0).clamp(0.0, 100.0) as u16).map_err(|e| e.to_string())? };
+        }
+
+        let (content, flags) = match &opts.ssml {
+            Some(ssml) => (HSTRING::from(ssml), SPF_ASYNC.0 as u32 | SPF_IS_XML.0 as u32),
+            None => (HSTRING::from(text), SPF_ASYNC.0 as u32),
+        };
+        unsafe { voice.Speak(&content, flags, None).map_err(|e| e.to_string())? };
+
+        if let Some(on_complete) = on_complete {
+            // SAPI speaks asynchronously; wait for it on a dedicated thread
+            // so the caller isn't blocked while the utterance plays out.
+            let voice_handle = voice.clone();
+            std::thread::spawn(move || {
+                let _ = unsafe { voice_handle.WaitUntilDone(u32::MAX) };
+                on_complete();
+            });
+        }
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), String> {
+        let voice = self.voice.lock().map_err(|e| e.to_string())?;
+        unsafe {
+            voice
+                .Speak(&HSTRING::new(), SPF_PURGEBEFORESPEAK.0 as u32, None)
+                .map_err(|e| e.to_string())?
+        };
+        Ok(())
+    }
+
+    fn voices(&self) -> Vec<Voice> {
+        enumerate_sapi_voices().unwrap_or_default()
+    }
+
+    fn features(&self) -> Features {
+        Features {
+            rate: true,
+            // SAPI has no direct pitch API; it's only reachable via SSML prosody tags.
+            pitch: false,
+            volume: true,
+            ssml: true,
+        }
+    }
+}
+
+/// Converts a COM-allocated `PWSTR` into an owned `String`, freeing the
+/// original allocation as SAPI's out-string conventions require.
+fn take_pwstr(raw: PWSTR) -> String {
+    let value = unsafe { raw.to_string() }.unwrap_or_default();
+    unsafe { CoTaskMemFree(Some(raw.0 as _)) };
+    value
+}
+
+/// Resolves a SAPI voice attribute's `Language` value (a hex LCID, e.g.
+/// `"409"`) to a BCP-47 tag (`"en-US"`) so it lines up with the
+/// `language_code` callers already use for macOS/Linux voices.
+fn lcid_to_language_tag(lcid_hex: &str) -> Option<String> {
+    let lcid = u32::from_str_radix(lcid_hex.trim(), 16).ok()?;
+    let mut buffer = [0u16; 85];
+    let len = unsafe { LCIDToLocaleName(lcid, Some(&mut buffer), 0) };
+    if len == 0 {
+        return None;
+    }
+    Some(String::from_utf16_lossy(&buffer[..len as usize - 1]))
+}
+
+/// Walks the `HKEY_LOCAL_MACHINE` voice token category SAPI registers
+/// installed voices under, returning one `Voice` per token.
+fn enumerate_sapi_voices() -> windows::core::Result<Vec<Voice>> {
+    unsafe {
+        let category: ISpObjectTokenCategory = CoCreateInstance(&SpObjectTokenCategory, None, CLSCTX_ALL)?;
+        category.SetId(SPCAT_VOICES, false)?;
+        let tokens = category.EnumTokens(PCWSTR::null(), PCWSTR::null())?;
+
+        let mut voices = Vec::new();
+        loop {
+            let mut token_slot: [Option<ISpObjectToken>; 1] = [None];
+            let mut fetched = 0u32;
+            if tokens.Next(1, token_slot.as_mut_ptr(), Some(&mut fetched)).is_err() || fetched == 0 {
+                break;
+            }
+            let Some(token) = token_slot[0].take() else { break };
+
+            let id = token.GetId().map(take_pwstr).unwrap_or_default();
+            let name = token
+                .GetStringValue(PCWSTR::null())
+                .map(take_pwstr)
+                .unwrap_or_else(|_| id.clone());
+
+            let attributes = token.OpenKey(w!("Attributes")).ok();
+            let language = attributes
+                .as_ref()
+                .and_then(|attrs| attrs.GetStringValue(w!("Language")).ok())
+                .map(take_pwstr)
+                .and_then(|lcid_hex| lcid_to_language_tag(&lcid_hex))
+                .unwrap_or_default();
+            let gender = attributes
+                .as_ref()
+                .and_then(|attrs| attrs.GetStringValue(w!("Gender")).ok())
+                .map(|raw| take_pwstr(raw).to_lowercase());
+
+            voices.push(Voice { id, name, language, gender });
+        }
+        Ok(voices)
+    }
+}